@@ -0,0 +1,62 @@
+use std::path::{Path, PathBuf};
+
+use oxc_diagnostics::Error;
+use serde_json::Value;
+
+use super::ESLintConfig;
+
+/// Config file names recognized while walking upward from a linted file's directory,
+/// checked in this priority order within each directory (mirrors the formats accepted by
+/// [`ESLintConfig::read_config`]).
+const CONFIG_FILE_NAMES: &[&str] =
+    &[".eslintrc", ".eslintrc.json", ".eslintrc.yml", ".eslintrc.yaml", ".eslintrc.toml"];
+
+/// Walks upward from `file`'s directory, collecting the path of the first config file found
+/// in each directory, stopping as soon as a config marked `"root": true` is encountered.
+/// Returns paths ordered from nearest (deepest) to furthest (outermost) — callers that need
+/// outermost-first merge order should iterate the result in reverse.
+pub fn discover_configs(file: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut configs = Vec::new();
+    let mut dir = file.parent().map(Path::to_path_buf);
+
+    while let Some(current_dir) = dir {
+        let found = CONFIG_FILE_NAMES.iter().map(|name| current_dir.join(name)).find(|p| p.is_file());
+
+        if let Some(path) = found {
+            let is_root = ESLintConfig::read_config(&path)?
+                .get("root")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+
+            configs.push(path);
+
+            if is_root {
+                break;
+            }
+        }
+
+        dir = current_dir.parent().map(Path::to_path_buf);
+    }
+
+    Ok(configs)
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::discover_configs;
+
+    #[test]
+    fn test_discover_configs_stops_at_root() {
+        let fixture_dir = env::current_dir().unwrap().join("fixtures/discover");
+        let file = fixture_dir.join("root/nested/foo.js");
+
+        let configs = discover_configs(&file).unwrap();
+
+        assert_eq!(configs, vec![
+            fixture_dir.join("root/nested/.eslintrc.json"),
+            fixture_dir.join("root/.eslintrc.json"),
+        ]);
+    }
+}
@@ -0,0 +1,31 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use std::path::PathBuf;
+
+use oxc_diagnostics::Error as OxcError;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse config")]
+pub struct FailedToParseConfigError(#[related] pub Vec<OxcError>);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse {1} config {0:?} with error {2:?}")]
+pub struct FailedToParseConfigJsonError(pub PathBuf, pub &'static str, pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse rule value {0:?} with error {1:?}")]
+pub struct FailedToParseRuleValueError(pub String, pub &'static str);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to resolve `extends` entry {0:?}: {1}")]
+pub struct FailedToResolveExtendsError(pub PathBuf, pub &'static str);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse `overrides` glob {0:?}: {1}")]
+pub struct FailedToParseOverrideGlobError(pub String, pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("{1} at `{0}`")]
+pub struct FailedToValidateConfigError(pub String, pub String);
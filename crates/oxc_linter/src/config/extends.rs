@@ -0,0 +1,317 @@
+use std::path::{Path, PathBuf};
+
+use oxc_diagnostics::Error;
+use rustc_hash::FxHashSet;
+use serde_json::Value;
+
+use super::{errors::FailedToResolveExtendsError, schema::known_rule_keys, ESLintConfig};
+
+/// Resolves the `extends` chain rooted at `path` and merges every base config into
+/// `root_json`, left-to-right, with `root_json`'s own `rules`/`settings` applied last so
+/// user entries always win. See [`merge_config_values`] for the merge semantics.
+pub fn resolve_extends(path: &Path, root_json: &Value) -> Result<Value, Error> {
+    let mut visited = FxHashSet::default();
+    if let Ok(canonical) = path.canonicalize() {
+        visited.insert(canonical);
+    }
+    resolve_extends_with_visited(path, root_json.clone(), &mut visited)
+}
+
+fn resolve_extends_with_visited(
+    path: &Path,
+    json: Value,
+    visited: &mut FxHashSet<PathBuf>,
+) -> Result<Value, Error> {
+    let Value::Object(ref root_object) = json else { return Ok(json) };
+    let Some(extends_value) = root_object.get("extends") else { return Ok(json) };
+
+    let entries: Vec<String> = match extends_value {
+        Value::String(entry) => vec![entry.clone()],
+        Value::Array(entries) => entries.iter().filter_map(|v| v.as_str().map(String::from)).collect(),
+        _ => Vec::default(),
+    };
+
+    let mut merged = Value::Object(serde_json::Map::default());
+    for entry in entries {
+        let base = resolve_extends_entry(path, &entry, visited)?;
+        merged = merge_config_values(merged, base);
+    }
+
+    Ok(merge_config_values(merged, json))
+}
+
+/// Resolves a single `extends` entry: a builtin preset name (`eslint:recommended`), a
+/// `plugin:...` preset, or a relative/absolute path to another config file.
+fn resolve_extends_entry(
+    from: &Path,
+    entry: &str,
+    visited: &mut FxHashSet<PathBuf>,
+) -> Result<Value, Error> {
+    if let Some(preset) = resolve_builtin_preset(entry) {
+        return Ok(preset);
+    }
+
+    let base_dir = from.parent().unwrap_or_else(|| Path::new("."));
+    let extend_path = base_dir.join(entry);
+
+    // Only the currently-active resolution path is tracked (pushed before recursing,
+    // popped after), not every path ever visited — two sibling `extends` entries (or two
+    // bases) are allowed to resolve the same shared file; only a file that extends itself
+    // transitively, through its own currently-open chain, is a cycle.
+    let canonical = extend_path.canonicalize().ok();
+    if let Some(canonical) = &canonical {
+        if !visited.insert(canonical.clone()) {
+            return Err(FailedToResolveExtendsError(
+                extend_path,
+                "cyclic `extends` chain detected",
+            )
+            .into());
+        }
+    }
+
+    let extend_json = ESLintConfig::read_config(&extend_path)?;
+    let is_root = extend_json.get("root").and_then(Value::as_bool).unwrap_or(false);
+
+    // A config marked `"root": true` terminates the chain: its rules/settings are used
+    // as-is without resolving any `extends` entries it might itself declare.
+    let result = if is_root {
+        Ok(extend_json)
+    } else {
+        resolve_extends_with_visited(&extend_path, extend_json, visited)
+    };
+
+    if let Some(canonical) = canonical {
+        visited.remove(&canonical);
+    }
+
+    result
+}
+
+/// The core rules enabled by ESLint's `"eslint:recommended"` preset. Curated by hand against
+/// upstream ESLint's recommended config since that table doesn't live in this repository;
+/// keep it in sync as ESLint's recommended set changes.
+const ESLINT_RECOMMENDED_RULES: &[&str] = &[
+    "no-cond-assign",
+    "no-constant-condition",
+    "no-control-regex",
+    "no-debugger",
+    "no-dupe-args",
+    "no-dupe-keys",
+    "no-duplicate-case",
+    "no-empty",
+    "no-empty-character-class",
+    "no-ex-assign",
+    "no-extra-boolean-cast",
+    "no-extra-semi",
+    "no-func-assign",
+    "no-invalid-regexp",
+    "no-irregular-whitespace",
+    "no-obj-calls",
+    "no-regex-spaces",
+    "no-sparse-arrays",
+    "no-unexpected-multiline",
+    "no-unreachable",
+    "no-unsafe-finally",
+    "no-unsafe-negation",
+    "use-isnan",
+    "valid-typeof",
+    "no-case-declarations",
+    "no-class-assign",
+    "no-compare-neg-zero",
+    "no-const-assign",
+    "no-delete-var",
+    "no-dupe-class-members",
+    "no-fallthrough",
+    "no-global-assign",
+    "no-mixed-spaces-and-tabs",
+    "no-new-symbol",
+    "no-octal",
+    "no-redeclare",
+    "no-self-assign",
+    "no-shadow-restricted-names",
+    "no-undef",
+    "no-unused-labels",
+    "no-unused-vars",
+    "no-useless-escape",
+    "require-yield",
+];
+
+/// Built-in presets keyed by the name ESLint users reference in `extends`, mapped to the
+/// core rule names they enable at `"error"`. `"eslint:recommended"` is backed by a real,
+/// curated table, intersected with [`known_rule_keys`] so a rule oxc doesn't implement yet
+/// never ends up in a resolved config — otherwise [`super::schema::validate`], which runs
+/// right after extends resolution, would reject it as an unknown rule and the single most
+/// common `extends` value would hard-fail every load. `"eslint:all"` and third-party
+/// `plugin:...` presets aren't modeled at all yet, so they resolve to an empty rule set
+/// rather than guessing; this is library code with no reporter to print a warning through, so
+/// unlike the previous revision this no longer calls `eprintln!` — a caller that wants to
+/// know what a preset contributed can inspect the resolved config's `rules`.
+fn resolve_builtin_preset(name: &str) -> Option<Value> {
+    let rules: Vec<&str> = match name {
+        "eslint:recommended" => {
+            let known_rule_keys = known_rule_keys();
+            ESLINT_RECOMMENDED_RULES
+                .iter()
+                .filter(|rule| known_rule_keys.contains(**rule))
+                .copied()
+                .collect()
+        }
+        "eslint:all" => Vec::new(),
+        _ if name.starts_with("plugin:") => Vec::new(),
+        _ => return None,
+    };
+
+    let rules_object = rules.into_iter().map(|rule| (rule.to_string(), Value::from("error"))).collect();
+
+    Some(Value::Object(
+        [("rules".to_string(), Value::Object(rules_object))].into_iter().collect(),
+    ))
+}
+
+/// Merges `overlay` on top of `base`: `rules` and `settings` objects are merged key-by-key
+/// (an overlay entry fully replaces the base entry under the same key), every other
+/// top-level key (`extends`, `root`, `overrides`, ...) is simply replaced when present in
+/// `overlay`. Also used to merge the cascade of directory-discovered configs in
+/// [`super::discover`].
+///
+/// `rules` keys are normalized with [`normalized_rule_key`] before merging, the same
+/// `(plugin_name, rule_name)` identity [`super::parse_rule_name`] later parses them into, so
+/// aliased spellings of the same rule (`@typescript-eslint/no-unused-vars` in a base vs
+/// `typescript/no-unused-vars` in an overlay) collapse to one entry instead of both surviving
+/// into `parse_rules` as separate, conflicting `ESLintRuleConfig`s.
+pub(super) fn merge_config_values(base: Value, overlay: Value) -> Value {
+    let Value::Object(mut base_object) = base else { return overlay };
+    let Value::Object(overlay_object) = overlay else { return Value::Object(base_object) };
+
+    for key in ["rules", "settings"] {
+        if let Some(Value::Object(overlay_map)) = overlay_object.get(key) {
+            let base_value =
+                base_object.entry(key).or_insert_with(|| Value::Object(serde_json::Map::default()));
+            if let Value::Object(base_map) = base_value {
+                if key == "rules" {
+                    *base_map = std::mem::take(base_map)
+                        .into_iter()
+                        .map(|(k, v)| (normalized_rule_key(&k), v))
+                        .collect();
+                }
+                for (k, v) in overlay_map {
+                    let key = if key == "rules" { normalized_rule_key(k) } else { k.clone() };
+                    base_map.insert(key, v.clone());
+                }
+            }
+        }
+    }
+
+    for (key, value) in overlay_object {
+        if key == "rules" || key == "settings" {
+            continue;
+        }
+        base_object.insert(key, value);
+    }
+
+    Value::Object(base_object)
+}
+
+/// Canonicalizes a `rules` key to the same `(plugin_name, rule_name)` identity
+/// [`super::parse_rule_name`] parses it into, so aliased spellings of the same rule merge
+/// into a single entry. The result is the key [`super::schema::known_rule_keys`] lists for
+/// that rule: a bare `eslint` rule stays bare, `jsx_a11y` rules become `jsx-a11y/rule_name`,
+/// everything else becomes `plugin_name/rule_name` with no `@` prefix or `typescript-eslint`
+/// aliasing left.
+pub(super) fn normalized_rule_key(name: &str) -> String {
+    let (plugin_name, rule_name) = super::parse_rule_name(name);
+    match plugin_name {
+        "eslint" => rule_name.to_string(),
+        "jsx_a11y" => format!("jsx-a11y/{rule_name}"),
+        _ => format!("{plugin_name}/{rule_name}"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use serde_json::json;
+
+    use super::{merge_config_values, resolve_extends};
+
+    #[test]
+    fn test_merge_config_values_overlay_wins_by_key() {
+        let base = json!({ "rules": { "no-debugger": "error", "no-console": "warn" } });
+        let overlay = json!({ "rules": { "no-debugger": "off" } });
+
+        let merged = merge_config_values(base, overlay);
+
+        assert_eq!(merged["rules"]["no-debugger"], json!("off"));
+        assert_eq!(merged["rules"]["no-console"], json!("warn"));
+    }
+
+    #[test]
+    fn test_merge_config_values_normalizes_aliased_rule_keys() {
+        let base = json!({ "rules": { "@typescript-eslint/no-unused-vars": "error" } });
+        let overlay = json!({ "rules": { "typescript/no-unused-vars": "off" } });
+
+        let merged = merge_config_values(base, overlay);
+
+        // Both spellings name the same rule, so only one entry should survive, carrying the
+        // overlay's value.
+        assert_eq!(merged["rules"].as_object().unwrap().len(), 1);
+        assert_eq!(merged["rules"]["typescript/no-unused-vars"], json!("off"));
+    }
+
+    #[test]
+    fn test_resolve_extends_own_rules_win() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/child.json");
+        let child_json = serde_json::from_str::<serde_json::Value>(
+            &std::fs::read_to_string(&fixture_path).unwrap(),
+        )
+        .unwrap();
+
+        let resolved = resolve_extends(&fixture_path, &child_json).unwrap();
+
+        // `child.json` sets `no-debugger` to "warn", overriding `base.json`'s "error".
+        assert_eq!(resolved["rules"]["no-debugger"], json!("warn"));
+        // `no-console` only comes from `base.json` and is preserved.
+        assert_eq!(resolved["rules"]["no-console"], json!("warn"));
+    }
+
+    #[test]
+    fn test_resolve_extends_detects_true_cycle() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/cycle_a.json");
+        let json = serde_json::from_str::<serde_json::Value>(
+            &std::fs::read_to_string(&fixture_path).unwrap(),
+        )
+        .unwrap();
+
+        assert!(resolve_extends(&fixture_path, &json).is_err());
+    }
+
+    #[test]
+    fn test_resolve_extends_allows_diamond_dependency() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/diamond_child.json");
+        let json = serde_json::from_str::<serde_json::Value>(
+            &std::fs::read_to_string(&fixture_path).unwrap(),
+        )
+        .unwrap();
+
+        // `diamond_a.json` and `diamond_b.json` both extend `diamond_base.json`; that's a
+        // shared dependency, not a cycle, and must resolve successfully.
+        let resolved = resolve_extends(&fixture_path, &json).unwrap();
+        assert_eq!(resolved["rules"]["no-debugger"], json!("error"));
+    }
+
+    #[test]
+    fn test_resolve_extends_eslint_recommended_passes_schema_validation() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/recommended.json");
+        let json = serde_json::from_str::<serde_json::Value>(
+            &std::fs::read_to_string(&fixture_path).unwrap(),
+        )
+        .unwrap();
+
+        // `eslint:recommended` only contributes rules oxc actually implements, so the
+        // resolved config must never fail `validate`'s unknown-rule check — the single most
+        // common `extends` value must not hard-fail every load.
+        let resolved = resolve_extends(&fixture_path, &json).unwrap();
+        assert!(super::super::schema::validate(&resolved).is_ok());
+    }
+}
@@ -1,6 +1,11 @@
 use std::path::Path;
 
 pub mod errors;
+mod discover;
+mod extends;
+mod overrides;
+mod schema;
+
 use oxc_diagnostics::{Error, FailedToOpenFileError, Report};
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
@@ -10,10 +15,42 @@ use crate::{rules::RuleEnum, AllowWarnDeny, JsxA11y, LintSettings};
 use self::errors::{
     FailedToParseConfigError, FailedToParseConfigJsonError, FailedToParseRuleValueError,
 };
+pub use self::overrides::ESLintOverride;
+pub use self::schema::schema;
 
 pub struct ESLintConfig {
     rules: Vec<ESLintRuleConfig>,
     settings: LintSettings,
+    overrides: Vec<ESLintOverride>,
+}
+
+/// The config encodings we accept, detected from the file extension of the path handed to
+/// [`ESLintConfig::new`]. Everything is normalized to a [`serde_json::Value`] before it
+/// reaches `parse_rules`/`parse_settings`, so unrecognized extensions fall back to JSON and
+/// surface a syntax error rather than silently misparsing.
+#[derive(Debug, Clone, Copy)]
+enum ConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl ConfigFormat {
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(std::ffi::OsStr::to_str) {
+            Some("yaml" | "yml") => Self::Yaml,
+            Some("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+            Self::Toml => "toml",
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -26,17 +63,55 @@ pub struct ESLintRuleConfig {
 
 impl ESLintConfig {
     pub fn new(path: &Path) -> Result<Self, Report> {
-        let json = Self::read_json(path)?;
+        let json = Self::read_config(path)?;
+        let json = extends::resolve_extends(path, &json)?;
+        schema::validate(&json)?;
         let rules = parse_rules(&json)?;
         let settings = parse_settings_from_root(&json);
-        Ok(Self { rules, settings })
+        let overrides = overrides::parse_overrides(&json)?;
+        Ok(Self { rules, settings, overrides })
+    }
+
+    /// Discovers and merges the first `.eslintrc*` of each directory walking upward from
+    /// `file`'s directory to the project root (or the nearest `"root": true` config), outermost config applied
+    /// first so deeper, closer-to-`file` configs win. This mirrors how ESLint resolves
+    /// configuration across a monorepo instead of requiring one global config path.
+    pub fn resolve_for(file: &Path) -> Result<Self, Report> {
+        let config_paths = discover::discover_configs(file)?;
+
+        let mut merged = Value::Object(serde_json::Map::default());
+        for path in config_paths.iter().rev() {
+            let json = Self::read_config(path)?;
+            let json = extends::resolve_extends(path, &json)?;
+            merged = extends::merge_config_values(merged, json);
+        }
+
+        schema::validate(&merged)?;
+        let rules = parse_rules(&merged)?;
+        let settings = parse_settings_from_root(&merged);
+        let overrides = overrides::parse_overrides(&merged)?;
+        Ok(Self { rules, settings, overrides })
     }
 
     pub fn settings(self) -> LintSettings {
         self.settings
     }
 
-    fn read_json(path: &Path) -> Result<serde_json::Value, Error> {
+    /// Returns the settings that apply when linting `file_path`: the base `settings` with
+    /// any matching `overrides` entry's `settings` layered on top, in declaration order.
+    pub fn settings_for(&self, file_path: &Path) -> LintSettings {
+        let mut settings = self.settings.clone();
+        for file_override in self.overrides.iter().filter(|o| o.matches(file_path)) {
+            settings = settings.layered_with(&file_override.settings);
+        }
+        settings
+    }
+
+    /// Reads a config file from disk, detecting its format (JSON, YAML or TOML) from the
+    /// file extension and returning a uniform [`serde_json::Value`] so the rest of the
+    /// config pipeline (`parse_rules`, `parse_settings`, `resolve_rule_value`) never needs
+    /// to know which encoding the user chose.
+    pub(crate) fn read_config(path: &Path) -> Result<serde_json::Value, Error> {
         let file = match std::fs::read_to_string(path) {
             Ok(file) => file,
             Err(e) => {
@@ -48,34 +123,40 @@ impl ESLintConfig {
             }
         };
 
-        serde_json::from_str::<serde_json::Value>(&file).map_err(|err| {
-            let guess = mime_guess::from_path(path);
-            let err = match guess.first() {
-                // syntax error
-                Some(mime) if mime.subtype() == "json" => err.to_string(),
-                Some(_) => "only json configuration is supported".to_string(),
-                None => {
-                    format!(
-                        "{err}, if the configuration is not a json file, please use json instead."
-                    )
-                }
-            };
-            FailedToParseConfigError(vec![Error::new(FailedToParseConfigJsonError(
-                path.to_path_buf(),
-                err,
-            ))])
-            .into()
-        })
+        let format = ConfigFormat::from_path(path);
+
+        match format {
+            ConfigFormat::Yaml => serde_yaml::from_str::<serde_json::Value>(&file)
+                .map_err(|err| Self::config_parse_error(path, format, err.to_string())),
+            ConfigFormat::Toml => toml::from_str::<serde_json::Value>(&file)
+                .map_err(|err| Self::config_parse_error(path, format, err.to_string())),
+            ConfigFormat::Json => serde_json::from_str::<serde_json::Value>(&file)
+                .map_err(|err| Self::config_parse_error(path, format, err.to_string())),
+        }
+    }
+
+    fn config_parse_error(path: &Path, format: ConfigFormat, err: String) -> Error {
+        FailedToParseConfigError(vec![Error::new(FailedToParseConfigJsonError(
+            path.to_path_buf(),
+            format.name(),
+            err,
+        ))])
+        .into()
     }
 
-    pub fn override_rules(&self, rules_to_override: &mut FxHashSet<RuleEnum>) {
+    /// Applies the base `rules` plus any `overrides` entries whose `files`/`excludedFiles`
+    /// globs match `file_path`, in declaration order, to `rules_to_override`.
+    pub fn override_rules(&self, file_path: &Path, rules_to_override: &mut FxHashSet<RuleEnum>) {
         let mut rules_to_replace = vec![];
         let mut rules_to_remove = vec![];
+        let effective_rules = self.effective_rules(file_path);
+
         for rule in rules_to_override.iter() {
             let plugin_name = rule.plugin_name();
             let rule_name = rule.name();
-            if let Some(rule_to_configure) =
-                self.rules.iter().find(|r| r.plugin_name == plugin_name && r.rule_name == rule_name)
+            if let Some(rule_to_configure) = effective_rules
+                .iter()
+                .find(|r| r.plugin_name == plugin_name && r.rule_name == rule_name)
             {
                 match rule_to_configure.severity {
                     AllowWarnDeny::Warn | AllowWarnDeny::Deny => {
@@ -94,6 +175,32 @@ impl ESLintConfig {
             rules_to_override.replace(rule);
         }
     }
+
+    /// Layers the `rules` of every `overrides` entry matching `file_path` on top of the
+    /// base `rules`, in declaration order, replacing entries with the same
+    /// `(plugin_name, rule_name)` key.
+    fn effective_rules(&self, file_path: &Path) -> Vec<&ESLintRuleConfig> {
+        let mut keys: Vec<(&str, &str)> = Vec::new();
+        let mut rules: Vec<&ESLintRuleConfig> = Vec::new();
+
+        let layers = std::iter::once(&self.rules).chain(
+            self.overrides.iter().filter(|o| o.matches(file_path)).map(|o| &o.rules),
+        );
+
+        for layer in layers {
+            for rule in layer {
+                let key = (rule.plugin_name.as_str(), rule.rule_name.as_str());
+                if let Some(pos) = keys.iter().position(|k| *k == key) {
+                    rules[pos] = rule;
+                } else {
+                    keys.push(key);
+                    rules.push(rule);
+                }
+            }
+        }
+
+        rules
+    }
 }
 
 fn parse_rules(root_json: &Value) -> Result<Vec<ESLintRuleConfig>, Error> {
@@ -126,32 +233,25 @@ fn parse_settings_from_root(root_json: &Value) -> LintSettings {
     parse_settings(settings_value)
 }
 
+/// Routes each `settings.<plugin>` namespace to its plugin's typed parser, keeping any
+/// namespace without one yet as an untyped passthrough (see [`LintSettings::get_raw`])
+/// instead of discarding it. Add a new plugin's field here as it gains typed settings.
 pub fn parse_settings(setting_value: &Value) -> LintSettings {
-    if let Value::Object(settings_object) = setting_value {
-        if let Some(Value::Object(jsx_a11y)) = settings_object.get("jsx-a11y") {
-            let mut jsx_a11y_setting =
-                JsxA11y { polymorphic_prop_name: None, components: FxHashMap::default() };
-
-            if let Some(Value::Object(components)) = jsx_a11y.get("components") {
-                let components_map: FxHashMap<String, String> = components
-                    .iter()
-                    .map(|(key, value)| (String::from(key), String::from(value.as_str().unwrap())))
-                    .collect();
-
-                jsx_a11y_setting.set_components(components_map);
-            }
+    let Value::Object(settings_object) = setting_value else { return LintSettings::default() };
 
-            if let Some(Value::String(polymorphic_prop_name)) = jsx_a11y.get("polymorphicPropName")
-            {
-                jsx_a11y_setting
-                    .set_polymorphic_prop_name(Some(String::from(polymorphic_prop_name)));
-            }
+    let mut jsx_a11y = JsxA11y::default();
+    let mut unknown = FxHashMap::default();
 
-            return LintSettings { jsx_a11y: jsx_a11y_setting };
+    for (plugin_name, value) in settings_object {
+        match plugin_name.as_str() {
+            "jsx-a11y" => jsx_a11y = JsxA11y::from_value(value),
+            _ => {
+                unknown.insert(plugin_name.clone(), value.clone());
+            }
         }
     }
 
-    LintSettings::default()
+    LintSettings::new(jsx_a11y, unknown)
 }
 
 fn parse_rule_name(name: &str) -> (&str, &str) {
@@ -203,7 +303,7 @@ fn resolve_rule_value(value: &serde_json::Value) -> Result<(AllowWarnDeny, Optio
 
 #[cfg(test)]
 mod test {
-    use super::parse_rules;
+    use super::{parse_rules, ESLintConfig};
     use std::env;
 
     #[test]
@@ -214,4 +314,15 @@ mod test {
         let rules = parse_rules(&file).unwrap();
         insta::assert_debug_snapshot!(rules);
     }
+
+    #[test]
+    fn test_read_config_dispatches_by_extension() {
+        let fixtures_dir = env::current_dir().unwrap().join("fixtures/config_formats");
+
+        let yaml = ESLintConfig::read_config(&fixtures_dir.join("eslintrc.yml")).unwrap();
+        assert_eq!(yaml["rules"]["no-debugger"], "warn");
+
+        let toml = ESLintConfig::read_config(&fixtures_dir.join("eslintrc.toml")).unwrap();
+        assert_eq!(toml["rules"]["no-debugger"], "warn");
+    }
 }
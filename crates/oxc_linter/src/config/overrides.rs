@@ -0,0 +1,119 @@
+use std::path::Path;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use oxc_diagnostics::Error;
+use serde_json::Value;
+
+use super::{errors::FailedToParseOverrideGlobError, parse_rules, parse_settings, ESLintRuleConfig};
+use crate::LintSettings;
+
+/// A single entry of a config's top-level `overrides` array: its own `rules`/`settings`,
+/// applied on top of the base config for every file matching `files` (and not matching
+/// `excludedFiles`). Globs are compiled once at config-load time so matching a linted file
+/// against many overrides stays cheap.
+pub struct ESLintOverride {
+    files: GlobSet,
+    excluded_files: Option<GlobSet>,
+    pub(super) rules: Vec<ESLintRuleConfig>,
+    pub(super) settings: LintSettings,
+}
+
+impl ESLintOverride {
+    pub fn matches(&self, file_path: &Path) -> bool {
+        if !self.files.is_match(file_path) {
+            return false;
+        }
+
+        if let Some(excluded_files) = &self.excluded_files {
+            if excluded_files.is_match(file_path) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+pub fn parse_overrides(root_json: &Value) -> Result<Vec<ESLintOverride>, Error> {
+    let Value::Object(root_object) = root_json else { return Ok(Vec::default()) };
+
+    let Some(Value::Array(overrides)) = root_object.get("overrides") else {
+        return Ok(Vec::default());
+    };
+
+    overrides.iter().map(parse_override).collect()
+}
+
+fn parse_override(value: &Value) -> Result<ESLintOverride, Error> {
+    let files = build_glob_set(value.get("files"))?;
+    let excluded_files = match value.get("excludedFiles") {
+        Some(value) => Some(build_glob_set(Some(value))?),
+        None => None,
+    };
+    let rules = parse_rules(value)?;
+    let settings = value.get("settings").map_or_else(LintSettings::default, parse_settings);
+
+    Ok(ESLintOverride { files, excluded_files, rules, settings })
+}
+
+fn build_glob_set(value: Option<&Value>) -> Result<GlobSet, Error> {
+    let patterns: Vec<&str> = match value {
+        Some(Value::String(pattern)) => vec![pattern.as_str()],
+        Some(Value::Array(patterns)) => patterns.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::default(),
+    };
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let normalized = normalize_pattern(pattern);
+        let glob = Glob::new(&normalized)
+            .map_err(|err| FailedToParseOverrideGlobError(pattern.to_string(), err.to_string()))?;
+        builder.add(glob);
+    }
+
+    builder
+        .build()
+        .map_err(|err| FailedToParseOverrideGlobError(String::new(), err.to_string()).into())
+}
+
+/// ESLint matches `overrides[].files` patterns against the linted file's full path, so a
+/// pattern with no directory component (e.g. `*.test.ts`) still matches nested files like
+/// `src/foo.test.ts`. `globset` anchors a bare pattern to a single path segment, so prefix
+/// it with `**/` unless the user already wrote a path-aware pattern.
+fn normalize_pattern(pattern: &str) -> String {
+    if pattern.contains('/') {
+        pattern.to_string()
+    } else {
+        format!("**/{pattern}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::parse_overrides;
+
+    #[test]
+    fn test_bare_pattern_matches_nested_files() {
+        let config = json!({ "overrides": [{ "files": ["*.test.ts"] }] });
+        let overrides = parse_overrides(&config).unwrap();
+
+        assert!(overrides[0].matches(Path::new("foo.test.ts")));
+        assert!(overrides[0].matches(Path::new("src/foo.test.ts")));
+        assert!(!overrides[0].matches(Path::new("foo.ts")));
+    }
+
+    #[test]
+    fn test_excluded_files_takes_precedence_over_files() {
+        let config = json!({
+            "overrides": [{ "files": ["*.ts"], "excludedFiles": ["*.d.ts"] }]
+        });
+        let overrides = parse_overrides(&config).unwrap();
+
+        assert!(overrides[0].matches(Path::new("src/foo.ts")));
+        assert!(!overrides[0].matches(Path::new("src/foo.d.ts")));
+    }
+}
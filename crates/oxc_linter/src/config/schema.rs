@@ -0,0 +1,228 @@
+use oxc_diagnostics::Error;
+use rustc_hash::FxHashSet;
+use serde_json::{json, Map, Value};
+
+use super::errors::{FailedToParseConfigError, FailedToValidateConfigError};
+use crate::rules::RuleEnum;
+
+const SEVERITIES: &[&str] = &["off", "warn", "error"];
+
+/// Returns the JSON Schema describing an oxlint config file: every known `plugin/rule` name,
+/// the allowed severity literals, the `settings.jsx-a11y` shape, `extends`, and `overrides`.
+/// Editors can point at this to get autocompletion and inline validation for oxc config
+/// files; [`validate`] enforces the same shape at config-load time.
+pub fn schema() -> Value {
+    let rule_properties: Map<String, Value> =
+        RuleEnum::iter().map(|rule| (rule_config_key(&rule), rule_value_schema())).collect();
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "oxlint configuration",
+        "type": "object",
+        "properties": {
+            "root": { "type": "boolean" },
+            "extends": { "type": ["string", "array"], "items": { "type": "string" } },
+            "rules": { "type": "object", "properties": rule_properties, "additionalProperties": false },
+            "settings": {
+                "type": "object",
+                "properties": {
+                    "jsx-a11y": {
+                        "type": "object",
+                        "properties": {
+                            "polymorphicPropName": { "type": "string" },
+                            "components": { "type": "object", "additionalProperties": { "type": "string" } },
+                        },
+                    },
+                },
+            },
+            "overrides": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["files"],
+                    "properties": {
+                        "files": { "type": ["string", "array"] },
+                        "excludedFiles": { "type": ["string", "array"] },
+                        "rules": { "type": "object" },
+                        "settings": { "type": "object" },
+                    },
+                },
+            },
+        },
+    })
+}
+
+/// The inverse of `parse_rule_name`: the key a user actually writes in a config's `rules`
+/// object for `rule` (a bare name for core `eslint` rules, `plugin-name/rule-name`
+/// otherwise — note `RuleEnum::plugin_name()` uses the snake_case `jsx_a11y`, but the key
+/// users write is hyphenated `jsx-a11y`).
+fn rule_config_key(rule: &RuleEnum) -> String {
+    match rule.plugin_name() {
+        "eslint" => rule.name().to_string(),
+        "jsx_a11y" => format!("jsx-a11y/{}", rule.name()),
+        plugin_name => format!("{plugin_name}/{}", rule.name()),
+    }
+}
+
+/// Every `rules` key a user can write, derived from `RuleEnum` the same way [`schema`] does.
+/// Used by [`validate`] to reject unknown/typo'd rule names instead of silently ignoring
+/// them (names are first normalized, so aliased spellings such as
+/// `@typescript-eslint/...` are accepted), and by [`super::extends`] to intersect builtin presets (e.g.
+/// `"eslint:recommended"`) against rules oxc actually implements, so a preset can't inject a
+/// key `validate` then rejects as unknown.
+pub(super) fn known_rule_keys() -> FxHashSet<String> {
+    RuleEnum::iter().map(|rule| rule_config_key(&rule)).collect()
+}
+
+fn rule_value_schema() -> Value {
+    json!({
+        "oneOf": [
+            { "enum": SEVERITIES },
+            { "type": "array", "minItems": 1, "items": [{ "enum": SEVERITIES }] },
+        ],
+    })
+}
+
+/// Validates a parsed config `Value` against [`schema`], reporting every violation as an
+/// `oxc_diagnostics::Error` carrying the JSON path (e.g. `rules["no-debugger"]`) and the
+/// expected shape, instead of failing fast on the first malformed entry.
+pub fn validate(root_json: &Value) -> Result<(), Error> {
+    let mut violations = Vec::new();
+
+    let Value::Object(root_object) = root_json else {
+        return Ok(());
+    };
+
+    let known_rule_keys = known_rule_keys();
+
+    if let Some(extends) = root_object.get("extends") {
+        if !matches!(extends, Value::String(_) | Value::Array(_)) {
+            violations.push(violation("extends", "expected a string or an array of strings"));
+        }
+    }
+
+    validate_rules_object(root_object.get("rules"), "rules", &known_rule_keys, &mut violations);
+
+    if let Some(jsx_a11y) = root_object.get("settings").and_then(|s| s.get("jsx-a11y")) {
+        if let Some(Value::String(_)) = jsx_a11y.get("polymorphicPropName") {
+            // valid
+        } else if jsx_a11y.get("polymorphicPropName").is_some() {
+            violations.push(violation(
+                "settings[\"jsx-a11y\"].polymorphicPropName",
+                "expected a string",
+            ));
+        }
+    }
+
+    if let Some(Value::Array(overrides)) = root_object.get("overrides") {
+        for (index, entry) in overrides.iter().enumerate() {
+            let prefix = format!("overrides[{index}]");
+
+            match entry.get("files") {
+                None => violations.push(violation(&prefix, "missing required `files`")),
+                Some(files) if !matches!(files, Value::String(_) | Value::Array(_)) => {
+                    violations.push(violation(
+                        &format!("{prefix}.files"),
+                        "expected a string or an array of strings",
+                    ));
+                }
+                _ => {}
+            }
+
+            if let Some(excluded_files) = entry.get("excludedFiles") {
+                if !matches!(excluded_files, Value::String(_) | Value::Array(_)) {
+                    violations.push(violation(
+                        &format!("{prefix}.excludedFiles"),
+                        "expected a string or an array of strings",
+                    ));
+                }
+            }
+
+            validate_rules_object(
+                entry.get("rules"),
+                &format!("{prefix}.rules"),
+                &known_rule_keys,
+                &mut violations,
+            );
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(FailedToParseConfigError(violations).into())
+    }
+}
+
+fn validate_rules_object(
+    rules: Option<&Value>,
+    path_prefix: &str,
+    known_rule_keys: &FxHashSet<String>,
+    violations: &mut Vec<Error>,
+) {
+    let Some(Value::Object(rules)) = rules else { return };
+
+    for (name, value) in rules {
+        if !known_rule_keys.contains(&super::extends::normalized_rule_key(name)) {
+            violations.push(violation(&format!("{path_prefix}[{name:?}]"), "unknown rule"));
+            continue;
+        }
+        if let Err(message) = validate_rule_value(value) {
+            violations.push(violation(&format!("{path_prefix}[{name:?}]"), message));
+        }
+    }
+}
+
+fn validate_rule_value(value: &Value) -> Result<(), &'static str> {
+    match value {
+        Value::String(s) if SEVERITIES.contains(&s.as_str()) => Ok(()),
+        Value::String(_) => Err("expected one of \"off\", \"warn\", \"error\""),
+        Value::Array(items) => match items.first() {
+            Some(Value::String(s)) if SEVERITIES.contains(&s.as_str()) => Ok(()),
+            Some(Value::String(_)) => Err("expected one of \"off\", \"warn\", \"error\""),
+            _ => Err("expected a non-empty array whose first item is a severity"),
+        },
+        _ => Err("expected a severity string or an array starting with a severity"),
+    }
+}
+
+fn violation(path: &str, message: &'static str) -> Error {
+    FailedToValidateConfigError(path.to_string(), message.to_string()).into()
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::validate;
+
+    #[test]
+    fn test_validate_rejects_unknown_rule_name() {
+        let config = json!({ "rules": { "definitely-not-a-real-rule": "error" } });
+
+        let err = validate(&config).unwrap_err();
+        assert!(format!("{err:?}").contains("rules[\"definitely-not-a-real-rule\"]"));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_severity() {
+        let config = json!({ "rules": { "no-debugger": "extremely-off" } });
+
+        // `no-debugger` is a known rule, so the error can only come from the severity check.
+        let err = validate(&config).unwrap_err();
+        assert!(format!("{err:?}").contains("expected one of"));
+    }
+
+    #[test]
+    fn test_validate_accepts_empty_config() {
+        assert!(validate(&json!({})).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_non_string_extends() {
+        let config = json!({ "extends": 123 });
+
+        let err = validate(&config).unwrap_err();
+        assert!(format!("{err:?}").contains("extends"));
+    }
+}
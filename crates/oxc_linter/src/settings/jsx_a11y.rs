@@ -0,0 +1,40 @@
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+/// Shared settings for the `jsx-a11y` plugin, read from `settings["jsx-a11y"]`.
+#[derive(Debug, Clone, Default)]
+pub struct JsxA11y {
+    pub polymorphic_prop_name: Option<String>,
+    pub components: FxHashMap<String, String>,
+}
+
+impl JsxA11y {
+    pub fn set_components(&mut self, components: FxHashMap<String, String>) {
+        self.components = components;
+    }
+
+    pub fn set_polymorphic_prop_name(&mut self, polymorphic_prop_name: Option<String>) {
+        self.polymorphic_prop_name = polymorphic_prop_name;
+    }
+
+    /// Deserializer hook used by the settings registry in [`crate::config`].
+    pub(crate) fn from_value(value: &Value) -> Self {
+        let mut settings = Self::default();
+
+        let Value::Object(object) = value else { return settings };
+
+        if let Some(Value::Object(components)) = object.get("components") {
+            let components_map = components
+                .iter()
+                .filter_map(|(key, value)| value.as_str().map(|value| (key.clone(), value.to_string())))
+                .collect();
+            settings.set_components(components_map);
+        }
+
+        if let Some(Value::String(polymorphic_prop_name)) = object.get("polymorphicPropName") {
+            settings.set_polymorphic_prop_name(Some(polymorphic_prop_name.clone()));
+        }
+
+        settings
+    }
+}
@@ -0,0 +1,85 @@
+mod jsx_a11y;
+
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+pub use self::jsx_a11y::JsxA11y;
+
+/// Shared, cross-rule settings parsed from a config's top-level `settings` object. Each
+/// plugin that needs typed settings gets its own field with a small `from_value`
+/// deserializer hook (see [`JsxA11y::from_value`]); plugins without one yet still get their
+/// raw `settings.<plugin>` value preserved and reachable via [`LintSettings::get_raw`]
+/// instead of being silently dropped.
+#[derive(Debug, Clone, Default)]
+pub struct LintSettings {
+    pub jsx_a11y: JsxA11y,
+    unknown: FxHashMap<String, Value>,
+}
+
+impl LintSettings {
+    pub(crate) fn new(jsx_a11y: JsxA11y, unknown: FxHashMap<String, Value>) -> Self {
+        Self { jsx_a11y, unknown }
+    }
+
+    /// Returns the untyped `settings.<plugin_name>` value for a plugin that doesn't have a
+    /// typed field on `LintSettings` yet.
+    pub fn get_raw(&self, plugin_name: &str) -> Option<&Value> {
+        self.unknown.get(plugin_name)
+    }
+
+    /// Layers `overlay` on top of `self`: a field set on `overlay` replaces the
+    /// corresponding field on `self`, an unset field keeps `self`'s value. Used to apply a
+    /// matching `overrides` entry's `settings` on top of the base config's settings.
+    pub(crate) fn layered_with(&self, overlay: &LintSettings) -> LintSettings {
+        let jsx_a11y = JsxA11y {
+            polymorphic_prop_name: overlay
+                .jsx_a11y
+                .polymorphic_prop_name
+                .clone()
+                .or_else(|| self.jsx_a11y.polymorphic_prop_name.clone()),
+            components: if overlay.jsx_a11y.components.is_empty() {
+                self.jsx_a11y.components.clone()
+            } else {
+                overlay.jsx_a11y.components.clone()
+            },
+        };
+
+        let mut unknown = self.unknown.clone();
+        for (plugin_name, value) in &overlay.unknown {
+            unknown.insert(plugin_name.clone(), value.clone());
+        }
+
+        LintSettings { jsx_a11y, unknown }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{JsxA11y, LintSettings};
+
+    #[test]
+    fn test_get_raw_passthrough_for_unknown_plugin() {
+        let mut unknown = rustc_hash::FxHashMap::default();
+        unknown.insert("react".to_string(), json!({ "version": "18.0" }));
+        let settings = LintSettings::new(JsxA11y::default(), unknown);
+
+        assert_eq!(settings.get_raw("react"), Some(&json!({ "version": "18.0" })));
+        assert_eq!(settings.get_raw("import"), None);
+    }
+
+    #[test]
+    fn test_layered_with_unset_overlay_field_keeps_base() {
+        let mut base_jsx_a11y = JsxA11y::default();
+        base_jsx_a11y.set_polymorphic_prop_name(Some("as".to_string()));
+        let base = LintSettings::new(base_jsx_a11y, rustc_hash::FxHashMap::default());
+
+        let overlay_jsx_a11y = JsxA11y::default();
+        let overlay = LintSettings::new(overlay_jsx_a11y, rustc_hash::FxHashMap::default());
+
+        // overlay doesn't set `polymorphicPropName`, so the base value is kept.
+        let merged = base.layered_with(&overlay);
+        assert_eq!(merged.jsx_a11y.polymorphic_prop_name, Some("as".to_string()));
+    }
+}